@@ -0,0 +1,141 @@
+use std::env;
+use std::error::Error;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use arboard::Clipboard;
+
+/// A system clipboard that can be read from and written to.
+///
+/// Implemented both by `arboard` directly and by shelling out to external
+/// tools, so the app keeps working on headless/broken X11 or Wayland
+/// sessions where `arboard` has nothing to talk to.
+pub trait ClipboardBackend {
+    /// Short name of the backend, surfaced in the UI.
+    fn name(&self) -> &str;
+    fn get(&mut self) -> Result<String, Box<dyn Error>>;
+    fn set(&mut self, text: &str) -> Result<(), Box<dyn Error>>;
+}
+
+struct ArboardBackend {
+    clipboard: Clipboard,
+}
+
+impl ClipboardBackend for ArboardBackend {
+    fn name(&self) -> &str {
+        "arboard"
+    }
+
+    fn get(&mut self) -> Result<String, Box<dyn Error>> {
+        Ok(self.clipboard.get_text()?)
+    }
+
+    fn set(&mut self, text: &str) -> Result<(), Box<dyn Error>> {
+        self.clipboard.set_text(text.to_string())?;
+        Ok(())
+    }
+}
+
+/// Drives an external clipboard tool (`xclip`, `xsel`, `wl-copy`/`wl-paste`, ...) as a subprocess.
+struct ExternalToolBackend {
+    name: String,
+    get_cmd: (String, Vec<String>),
+    set_cmd: (String, Vec<String>),
+}
+
+impl ClipboardBackend for ExternalToolBackend {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get(&mut self) -> Result<String, Box<dyn Error>> {
+        let (program, args) = &self.get_cmd;
+        let output = Command::new(program).args(args).output()?;
+        if !output.status.success() {
+            return Err(format!("{program} exited with {}", output.status).into());
+        }
+        Ok(String::from_utf8(output.stdout)?)
+    }
+
+    fn set(&mut self, text: &str) -> Result<(), Box<dyn Error>> {
+        let (program, args) = &self.set_cmd;
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .spawn()?;
+        child
+            .stdin
+            .take()
+            .ok_or("failed to open stdin for clipboard tool")?
+            .write_all(text.as_bytes())?;
+        let status = child.wait()?;
+        if !status.success() {
+            return Err(format!("{program} exited with {status}").into());
+        }
+        Ok(())
+    }
+}
+
+/// A backend for when neither `arboard` nor any external tool is usable; every call fails.
+struct UnavailableBackend;
+
+impl ClipboardBackend for UnavailableBackend {
+    fn name(&self) -> &str {
+        "unavailable"
+    }
+
+    fn get(&mut self) -> Result<String, Box<dyn Error>> {
+        Err("Clipboard unavailable".into())
+    }
+
+    fn set(&mut self, _text: &str) -> Result<(), Box<dyn Error>> {
+        Err("Clipboard unavailable".into())
+    }
+}
+
+/// Picks the best clipboard backend available in the current session.
+///
+/// Prefers `arboard` (works out of the box on a normal X11/Wayland/Windows/macOS
+/// session). When that fails to initialize, falls back to shelling out to
+/// `wl-copy`/`wl-paste` under Wayland or `xclip`/`xsel` under X11, detected by
+/// probing `$PATH` and `$WAYLAND_DISPLAY`/`$DISPLAY`.
+pub fn detect_backend() -> Box<dyn ClipboardBackend> {
+    if let Ok(clipboard) = Clipboard::new() {
+        return Box::new(ArboardBackend { clipboard });
+    }
+
+    if env::var_os("WAYLAND_DISPLAY").is_some() && binary_on_path("wl-copy") && binary_on_path("wl-paste") {
+        return Box::new(ExternalToolBackend {
+            name: "wl-clipboard".to_string(),
+            get_cmd: ("wl-paste".to_string(), vec!["--no-newline".to_string()]),
+            set_cmd: ("wl-copy".to_string(), vec![]),
+        });
+    }
+
+    if env::var_os("DISPLAY").is_some() {
+        if binary_on_path("xclip") {
+            return Box::new(ExternalToolBackend {
+                name: "xclip".to_string(),
+                get_cmd: ("xclip".to_string(), vec!["-selection".to_string(), "clipboard".to_string(), "-o".to_string()]),
+                set_cmd: ("xclip".to_string(), vec!["-selection".to_string(), "clipboard".to_string()]),
+            });
+        }
+
+        if binary_on_path("xsel") {
+            return Box::new(ExternalToolBackend {
+                name: "xsel".to_string(),
+                get_cmd: ("xsel".to_string(), vec!["--clipboard".to_string(), "--output".to_string()]),
+                set_cmd: ("xsel".to_string(), vec!["--clipboard".to_string(), "--input".to_string()]),
+            });
+        }
+    }
+
+    Box::new(UnavailableBackend)
+}
+
+/// Whether `bin` resolves to an executable file somewhere on `$PATH`.
+fn binary_on_path(bin: &str) -> bool {
+    env::var_os("PATH")
+        .map(|paths| env::split_paths(&paths).any(|dir| dir.join(bin).is_file()))
+        .unwrap_or(false)
+}
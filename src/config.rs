@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+
+/// Name of the TOML file looked up in the working directory at startup.
+pub const CONFIG_FILE_NAME: &str = "keybindings.toml";
+
+/// A single named transformation that can be chained into a key binding, much like
+/// Alacritty's hint bindings map a key to a list of actions.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type")]
+pub enum Transform {
+    RegexReplace { pattern: String, replacement: String },
+    Trim,
+    Lowercase,
+    Uppercase,
+    RemoveExtraSpaces,
+    CopyToClipboard,
+    PasteFromClipboard,
+}
+
+impl Transform {
+    /// Short label used to build the instructions bar and the audit log.
+    pub fn label(&self) -> &str {
+        match self {
+            Transform::RegexReplace { .. } => "regex replace",
+            Transform::Trim => "trim",
+            Transform::Lowercase => "lowercase",
+            Transform::Uppercase => "uppercase",
+            Transform::RemoveExtraSpaces => "remove extra spaces",
+            Transform::CopyToClipboard => "copy to clipboard",
+            Transform::PasteFromClipboard => "paste from clipboard",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RawConfig {
+    #[serde(default)]
+    bindings: Vec<RawBinding>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawBinding {
+    key: String,
+    #[serde(default)]
+    modifiers: Vec<String>,
+    transforms: Vec<Transform>,
+}
+
+/// The user's parsed, ready-to-use key bindings: each key event maps to the ordered
+/// list of transforms it triggers when pressed in normal mode.
+#[derive(Debug, Default, Clone)]
+pub struct Config {
+    pub bindings: HashMap<KeyEvent, Vec<Transform>>,
+}
+
+impl Config {
+    /// Loads bindings from `path`. Falls back to [`Config::default_bindings`] if the
+    /// file is missing or fails to parse, so the app keeps its familiar defaults
+    /// out of the box.
+    pub fn load(path: &Path) -> Config {
+        let Ok(contents) = fs::read_to_string(path) else {
+            return Config::default_bindings();
+        };
+
+        let Ok(raw) = toml::from_str::<RawConfig>(&contents) else {
+            return Config::default_bindings();
+        };
+
+        let bindings = raw
+            .bindings
+            .into_iter()
+            .filter_map(|binding| {
+                let key_event = parse_key_event(&binding.key, &binding.modifiers)?;
+                Some((key_event, binding.transforms))
+            })
+            .collect();
+
+        Config { bindings }
+    }
+
+    /// The bindings the app shipped with before it grew a config file: F1 chains
+    /// paste -> remove extra spaces -> copy (the old "Quick Fix"), F2-F4 are its
+    /// individual steps.
+    pub fn default_bindings() -> Config {
+        let mut bindings = HashMap::new();
+        bindings.insert(
+            KeyEvent::new(KeyCode::F(1), KeyModifiers::NONE),
+            vec![Transform::PasteFromClipboard, Transform::RemoveExtraSpaces, Transform::CopyToClipboard],
+        );
+        bindings.insert(KeyEvent::new(KeyCode::F(2), KeyModifiers::NONE), vec![Transform::PasteFromClipboard]);
+        bindings.insert(KeyEvent::new(KeyCode::F(3), KeyModifiers::NONE), vec![Transform::RemoveExtraSpaces]);
+        bindings.insert(KeyEvent::new(KeyCode::F(4), KeyModifiers::NONE), vec![Transform::CopyToClipboard]);
+        Config { bindings }
+    }
+}
+
+/// Parses a crossterm-style key name (`"F1"`, `"Enter"`, `"a"`, ...) plus a list of
+/// modifier names (`"Control"`, `"Shift"`, `"Alt"`) into a `KeyEvent`.
+fn parse_key_event(key: &str, modifiers: &[String]) -> Option<KeyEvent> {
+    let code = parse_key_code(key)?;
+    let mods = modifiers.iter().fold(KeyModifiers::NONE, |acc, name| {
+        acc | match name.to_lowercase().as_str() {
+            "control" | "ctrl" => KeyModifiers::CONTROL,
+            "shift" => KeyModifiers::SHIFT,
+            "alt" => KeyModifiers::ALT,
+            _ => KeyModifiers::NONE,
+        }
+    });
+    Some(KeyEvent::new(code, mods))
+}
+
+fn parse_key_code(key: &str) -> Option<KeyCode> {
+    if let Some(n) = key.strip_prefix('F').and_then(|n| n.parse::<u8>().ok()) {
+        return Some(KeyCode::F(n));
+    }
+
+    match key {
+        "Enter" => Some(KeyCode::Enter),
+        "Esc" | "Escape" => Some(KeyCode::Esc),
+        "Tab" => Some(KeyCode::Tab),
+        "Backspace" => Some(KeyCode::Backspace),
+        "Delete" => Some(KeyCode::Delete),
+        "Left" => Some(KeyCode::Left),
+        "Right" => Some(KeyCode::Right),
+        "Home" => Some(KeyCode::Home),
+        "End" => Some(KeyCode::End),
+        _ if key.chars().count() == 1 => key.chars().next().map(KeyCode::Char),
+        _ => None,
+    }
+}
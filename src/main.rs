@@ -1,9 +1,18 @@
-use arboard::Clipboard;
+use std::path::Path;
+
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use ratatui::{prelude::*, widgets::{Block, Borders, Paragraph, Wrap}, DefaultTerminal};
 use regex::Regex;
 
+mod clipboard;
+use clipboard::ClipboardBackend;
+
+mod config;
+use config::{Config, Transform};
+
 const ACCENT_COLOR: Color = Color::Red;
+/// Maximum number of snapshots kept on the undo/redo stacks, to bound memory.
+const UNDO_HISTORY_LIMIT: usize = 100;
 
 trait NiceKeyEvent {
     /// Returns a string representation of the key event for display purposes.
@@ -21,11 +30,15 @@ impl NiceKeyEvent for KeyEvent {
 }
 
 enum Action {
-    PasteFromClipboard,
-    RemoveExtraSpaces,
-    CopyToClipboard,
     ClearText,
-    QuickFix,
+    EnterEditMode,
+    ExitEditMode,
+    OpenFindReplace,
+    ReplaceRegex,
+    CancelFindReplace,
+    Undo,
+    Redo,
+    CustomTransform(String),
     Exit,
 }
 
@@ -33,26 +46,78 @@ impl Action {
     /// Returns a string representation of the action for display purposes.
     fn as_str(&self) -> &str {
         match self {
-            Action::PasteFromClipboard => "Pasted text from clipboard",
-            Action::RemoveExtraSpaces => "Removed extra spaces",
-            Action::CopyToClipboard => "Copied text to clipboard",
             Action::ClearText => "Cleared text",
-            Action::QuickFix => "Quick fix applied. Your clipboard was updated.",
+            Action::EnterEditMode => "Entered edit mode",
+            Action::ExitEditMode => "Exited edit mode",
+            Action::OpenFindReplace => "Opened find & replace prompt",
+            Action::ReplaceRegex => "Applied regex replace",
+            Action::CancelFindReplace => "Cancelled find & replace",
+            Action::Undo => "Restored previous text (undo)",
+            Action::Redo => "Restored next text (redo)",
+            Action::CustomTransform(description) => description.as_str(),
             Action::Exit => "Exiting application",
         }
     }
 }
 
-#[derive(Default)]
+/// Whether keystrokes drive the F-key actions or are inserted into the text box
+/// or into one of the find & replace prompt's fields.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum InputMode {
+    #[default]
+    Normal,
+    Editing,
+    EditingPattern,
+    EditingReplacement,
+}
+
+impl InputMode {
+    fn as_str(&self) -> &str {
+        match self {
+            InputMode::Normal => "Normal",
+            InputMode::Editing => "Editing",
+            InputMode::EditingPattern => "Find & Replace (pattern)",
+            InputMode::EditingReplacement => "Find & Replace (replacement)",
+        }
+    }
+}
+
 struct App {
     exit: bool,
-    clipboard: Option<Clipboard>,
+    clipboard: Box<dyn ClipboardBackend>,
     pub text: String,
+    pub cursor_position: usize,
+    pub input_mode: InputMode,
+    pub pattern_input: String,
+    pub replacement_input: String,
+    pub undo_stack: Vec<String>,
+    pub redo_stack: Vec<String>,
+    pub config: Config,
     pub last_pressed_key: Option<KeyEvent>,
     pub last_error: Option<String>,
     pub last_action: Option<Action>,
 }
 
+impl Default for App {
+    fn default() -> Self {
+        Self {
+            exit: false,
+            clipboard: clipboard::detect_backend(),
+            text: String::new(),
+            cursor_position: 0,
+            input_mode: InputMode::default(),
+            pattern_input: String::new(),
+            replacement_input: String::new(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            config: Config::load(Path::new(config::CONFIG_FILE_NAME)),
+            last_pressed_key: None,
+            last_error: None,
+            last_action: None,
+        }
+    }
+}
+
 // The basic application structure. Does not change. Can be copy-pasted into any application.
 impl App {
     /// runs the application's main loop until the user quits
@@ -66,6 +131,87 @@ impl App {
 
     fn draw(&self, frame: &mut Frame) {
         frame.render_widget(self, frame.area());
+
+        match self.input_mode {
+            InputMode::Editing => {
+                let textbox = self.textbox_area(frame.area());
+                let (col, row) = self.cursor_screen_position();
+                frame.set_cursor_position(Position::new(textbox.x + col, textbox.y + row));
+            },
+            InputMode::EditingPattern | InputMode::EditingReplacement => {
+                let (pattern_area, replacement_area) = self.prompt_field_areas(frame.area());
+                let (area, text) = match self.input_mode {
+                    InputMode::EditingPattern => (pattern_area, &self.pattern_input),
+                    _ => (replacement_area, &self.replacement_input),
+                };
+                frame.set_cursor_position(Position::new(
+                    area.x + text.chars().count() as u16,
+                    area.y,
+                ));
+            },
+            InputMode::Normal => {},
+        }
+    }
+
+    /// Re-derives the text box's inner rect from the same layout used by `Widget for &App`.
+    fn textbox_area(&self, area: Rect) -> Rect {
+        let [_instructions, _prompt, textbox, _audit_log, _last_error] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(5),
+            Constraint::Length(3),
+            Constraint::Length(3),
+        ]).vertical_margin(0).horizontal_margin(1).areas(area);
+
+        textbox.inner(Margin::new(1, 1))
+    }
+
+    /// Re-derives the find/replace prompt's pattern and replacement input rects.
+    fn prompt_field_areas(&self, area: Rect) -> (Rect, Rect) {
+        let [_instructions, prompt, _textbox, _audit_log, _last_error] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(5),
+            Constraint::Length(3),
+            Constraint::Length(3),
+        ]).vertical_margin(0).horizontal_margin(1).areas(area);
+
+        let [pattern, replacement] = Layout::horizontal([
+            Constraint::Percentage(50),
+            Constraint::Percentage(50),
+        ]).areas(prompt);
+        let pattern = pattern.inner(Margin::new(1, 1));
+        let replacement = replacement.inner(Margin::new(1, 1));
+
+        let pattern_field = Rect::new(
+            pattern.x + "Pattern: ".len() as u16,
+            pattern.y,
+            pattern.width.saturating_sub("Pattern: ".len() as u16),
+            1,
+        );
+        let replacement_field = Rect::new(
+            replacement.x + "Replacement: ".len() as u16,
+            replacement.y,
+            replacement.width.saturating_sub("Replacement: ".len() as u16),
+            1,
+        );
+
+        (pattern_field, replacement_field)
+    }
+
+    /// Column/row of the cursor within the text box, accounting for newlines in `self.text`.
+    fn cursor_screen_position(&self) -> (u16, u16) {
+        let mut row: u16 = 0;
+        let mut col: u16 = 0;
+        for ch in self.text.chars().take(self.cursor_position) {
+            if ch == '\n' {
+                row += 1;
+                col = 0;
+            } else {
+                col += 1;
+            }
+        }
+        (col, row)
     }
 
     /// updates the application's state based on user input
@@ -82,57 +228,236 @@ impl App {
 // The user logic for the application.
 impl App {
     fn paste_text_from_clipboard(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        let mut clipboard = Clipboard::new()?;
-        if let Ok(clip_text) = clipboard.get_text() {
-            self.text = clip_text;
-        }
+        self.text = self.clipboard.get()?;
+        self.reset_cursor();
         Ok(())
     }
 
     fn copy_text_to_clipboard(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        if let Some(clipboard) = &mut self.clipboard {
-            clipboard.set_text(self.text.clone())?;
-        } else {
-            return Err("Clipboard unavailable".into());
+        self.clipboard.set(&self.text)
+    }
+
+    /// Compiles `pattern` and replaces every match in `self.text` with `replacement`,
+    /// which may reference capture groups (`$1`, `${name}`) as supported by `Regex::replace_all`.
+    fn apply_regex_replace(&mut self, pattern: &str, replacement: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let re = Regex::new(pattern)?;
+        self.text = re.replace_all(&self.text, replacement).to_string();
+        Ok(())
+    }
+
+    /// The "remove extra spaces" preset, expressed as an entry in the general find/replace machinery.
+    fn remove_extra_spaces(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.apply_regex_replace(r"\s+", " ")
+    }
+
+    /// Runs a single configured transformation against `self.text` (or the clipboard).
+    fn apply_transform(&mut self, transform: &Transform) -> Result<(), Box<dyn std::error::Error>> {
+        match transform {
+            Transform::RegexReplace { pattern, replacement } => self.apply_regex_replace(pattern, replacement),
+            Transform::Trim => {
+                self.text = self.text.trim().to_string();
+                Ok(())
+            },
+            Transform::Lowercase => {
+                self.text = self.text.to_lowercase();
+                Ok(())
+            },
+            Transform::Uppercase => {
+                self.text = self.text.to_uppercase();
+                Ok(())
+            },
+            Transform::RemoveExtraSpaces => self.remove_extra_spaces(),
+            Transform::CopyToClipboard => self.copy_text_to_clipboard(),
+            Transform::PasteFromClipboard => self.paste_text_from_clipboard(),
+        }
+    }
+
+    /// Runs a chain of configured transformations in order, e.g. paste -> collapse
+    /// whitespace -> lowercase -> copy, bound to a single keystroke.
+    fn apply_transforms(&mut self, transforms: &[Transform]) -> Result<(), Box<dyn std::error::Error>> {
+        for transform in transforms {
+            self.apply_transform(transform)?;
         }
         Ok(())
     }
 
-    fn remove_extra_spaces(&mut self) {
-        let re = Regex::new(r"\s+").unwrap();
-        self.text = re.replace_all(&self.text, " ").to_string();
+    /// Builds the Normal-mode instructions bar from the loaded keybindings config,
+    /// plus the handful of built-in controls (clear, editing, find & replace, undo/redo, exit)
+    /// that aren't expressed as transforms.
+    fn normal_mode_instructions(&self) -> String {
+        let mut bindings: Vec<(String, String)> = self.config.bindings.iter()
+            .map(|(key_event, transforms)| {
+                let labels = transforms.iter().map(Transform::label).collect::<Vec<_>>().join(" -> ");
+                (key_event.to_nice_string(), labels)
+            })
+            .collect();
+        bindings.sort();
+
+        let mut parts: Vec<String> = bindings.into_iter()
+            .map(|(key, labels)| format!("<{key}> {labels}"))
+            .collect();
+
+        parts.push("<F5> clear text".to_string());
+        parts.push("<F6> edit".to_string());
+        parts.push("<F7> find & replace".to_string());
+        parts.push("<CTRL+z> undo".to_string());
+        parts.push("<CTRL+y> redo".to_string());
+        parts.push("<CTRL+c> exit".to_string());
+        parts.join(" | ")
     }
 
     fn clear_text(&mut self) {
         self.text.clear();
+        self.reset_cursor();
+    }
+
+    fn char_count(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    /// Converts the char-based `cursor_position` into a byte index into `self.text`.
+    fn byte_index(&self) -> usize {
+        self.text
+            .char_indices()
+            .map(|(i, _)| i)
+            .nth(self.cursor_position)
+            .unwrap_or(self.text.len())
+    }
+
+    fn clamp_cursor(&self, new_cursor_position: usize) -> usize {
+        new_cursor_position.clamp(0, self.char_count())
+    }
+
+    fn reset_cursor(&mut self) {
+        self.cursor_position = 0;
+    }
+
+    /// Snapshots `self.text` onto the undo stack before a mutating action, bounding its size
+    /// and discarding any redo history made stale by the new edit.
+    fn push_undo_snapshot(&mut self) {
+        self.undo_stack.push(self.text.clone());
+        if self.undo_stack.len() > UNDO_HISTORY_LIMIT {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+    }
+
+    fn undo(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let previous = self.undo_stack.pop().ok_or("Nothing to undo")?;
+        self.redo_stack.push(std::mem::replace(&mut self.text, previous));
+        self.cursor_position = self.clamp_cursor(self.cursor_position);
+        Ok(())
+    }
+
+    fn redo(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let next = self.redo_stack.pop().ok_or("Nothing to redo")?;
+        self.undo_stack.push(std::mem::replace(&mut self.text, next));
+        self.cursor_position = self.clamp_cursor(self.cursor_position);
+        Ok(())
+    }
+
+    fn move_cursor_left(&mut self) {
+        self.cursor_position = self.clamp_cursor(self.cursor_position.saturating_sub(1));
+    }
+
+    fn move_cursor_right(&mut self) {
+        self.cursor_position = self.clamp_cursor(self.cursor_position.saturating_add(1));
+    }
+
+    /// Moves the cursor to the start of the current (possibly wrapped-by-newline) line.
+    fn move_cursor_home(&mut self) {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut pos = self.cursor_position;
+        while pos > 0 && chars[pos - 1] != '\n' {
+            pos -= 1;
+        }
+        self.cursor_position = pos;
+    }
+
+    /// Moves the cursor to the end of the current line.
+    fn move_cursor_end(&mut self) {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut pos = self.cursor_position;
+        while pos < chars.len() && chars[pos] != '\n' {
+            pos += 1;
+        }
+        self.cursor_position = pos;
+    }
+
+    fn enter_char(&mut self, new_char: char) {
+        let index = self.byte_index();
+        self.text.insert(index, new_char);
+        self.move_cursor_right();
+    }
+
+    /// Deletes the character to the left of the cursor (backspace).
+    fn delete_char_before(&mut self) {
+        if self.cursor_position == 0 {
+            return;
+        }
+        let before = self.text.chars().take(self.cursor_position - 1);
+        let after = self.text.chars().skip(self.cursor_position);
+        self.text = before.chain(after).collect();
+        self.move_cursor_left();
+    }
+
+    /// Deletes the character under/after the cursor (delete).
+    fn delete_char_after(&mut self) {
+        if self.cursor_position >= self.char_count() {
+            return;
+        }
+        let before = self.text.chars().take(self.cursor_position);
+        let after = self.text.chars().skip(self.cursor_position + 1);
+        self.text = before.chain(after).collect();
     }
 
     fn on_key_pressed(&mut self, key_event: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
         self.last_pressed_key = Some(key_event);
 
-        let result = match (key_event.code, key_event.modifiers) {
-            (KeyCode::F(1), _) => {
-                self.last_action = Some(Action::QuickFix);
-                self.paste_text_from_clipboard()?;
-                self.remove_extra_spaces();
-                self.copy_text_to_clipboard()
+        let result = match self.input_mode {
+            InputMode::Editing => self.on_key_pressed_editing(key_event),
+            InputMode::EditingPattern => self.on_key_pressed_find_replace_pattern(key_event),
+            InputMode::EditingReplacement => self.on_key_pressed_find_replace_replacement(key_event),
+            InputMode::Normal => self.on_key_pressed_normal(key_event),
+        };
+
+        // store the last error message if it occurred
+        if let Err(ref e) = result {
+            let date = chrono::Local::now();
+            let pretty_date = date.format("%Y-%m-%d %H:%M:%S").to_string();
+            self.last_error = Some(format!("{}: {}", pretty_date, e.to_string()));
+        }
+
+        result
+    }
+
+    fn on_key_pressed_normal(&mut self, key_event: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::F(5), _) => {
+                self.last_action = Some(Action::ClearText);
+                self.push_undo_snapshot();
+                self.clear_text();
+                Ok(())
             },
-            (KeyCode::F(2), _) => {
-                self.last_action = Some(Action::PasteFromClipboard);
-                self.paste_text_from_clipboard()
+            (KeyCode::Char('z'), KeyModifiers::CONTROL) => {
+                self.last_action = Some(Action::Undo);
+                self.undo()
             },
-            (KeyCode::F(3), _) => {
-                self.last_action = Some(Action::RemoveExtraSpaces);
-                self.remove_extra_spaces();
-                Ok(())
+            (KeyCode::Char('y'), KeyModifiers::CONTROL) => {
+                self.last_action = Some(Action::Redo);
+                self.redo()
             },
-            (KeyCode::F(4), _) => {
-                self.last_action = Some(Action::CopyToClipboard);
-                self.copy_text_to_clipboard()
+            (KeyCode::F(6), _) => {
+                self.last_action = Some(Action::EnterEditMode);
+                self.input_mode = InputMode::Editing;
+                self.cursor_position = self.clamp_cursor(self.char_count());
+                Ok(())
             },
-            (KeyCode::F(5), _) => {
-                self.last_action = Some(Action::ClearText);
-                self.clear_text();
+            (KeyCode::F(7), _) => {
+                self.last_action = Some(Action::OpenFindReplace);
+                self.pattern_input.clear();
+                self.replacement_input.clear();
+                self.input_mode = InputMode::EditingPattern;
                 Ok(())
             },
             (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
@@ -140,17 +465,116 @@ impl App {
                 self.request_exit();
                 Ok(())
             },
-            _ => Ok(())
-        };
+            _ => {
+                let Some(transforms) = self.config.bindings.get(&key_event).cloned() else {
+                    return Ok(());
+                };
+                let description = format!("Applied: {}", transforms.iter().map(Transform::label).collect::<Vec<_>>().join(" -> "));
+                self.last_action = Some(Action::CustomTransform(description));
+                self.push_undo_snapshot();
+                self.apply_transforms(&transforms)
+            },
+        }
+    }
 
-        // store the last error message if it occurred
-        if let Err(ref e) = result {
-            let date = chrono::Local::now();
-            let pretty_date = date.format("%Y-%m-%d %H:%M:%S").to_string();
-            self.last_error = Some(format!("{}: {}", pretty_date, e.to_string()));
+    fn on_key_pressed_editing(&mut self, key_event: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
+                self.last_action = Some(Action::Exit);
+                self.request_exit();
+            },
+            (KeyCode::Char('z'), KeyModifiers::CONTROL) => {
+                self.last_action = Some(Action::Undo);
+                self.undo()?;
+            },
+            (KeyCode::Char('y'), KeyModifiers::CONTROL) => {
+                self.last_action = Some(Action::Redo);
+                self.redo()?;
+            },
+            (KeyCode::Esc, _) => {
+                self.last_action = Some(Action::ExitEditMode);
+                self.input_mode = InputMode::Normal;
+            },
+            (KeyCode::Char(c), modifiers) if !modifiers.contains(KeyModifiers::CONTROL) => {
+                self.push_undo_snapshot();
+                self.enter_char(c);
+            },
+            (KeyCode::Backspace, _) => {
+                self.push_undo_snapshot();
+                self.delete_char_before();
+            },
+            (KeyCode::Delete, _) => {
+                self.push_undo_snapshot();
+                self.delete_char_after();
+            },
+            (KeyCode::Left, _) => self.move_cursor_left(),
+            (KeyCode::Right, _) => self.move_cursor_right(),
+            (KeyCode::Home, _) => self.move_cursor_home(),
+            (KeyCode::End, _) => self.move_cursor_end(),
+            _ => {}
         }
+        Ok(())
+    }
 
-        result
+    fn on_key_pressed_find_replace_pattern(&mut self, key_event: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
+                self.last_action = Some(Action::Exit);
+                self.request_exit();
+            },
+            (KeyCode::Esc, _) => {
+                self.last_action = Some(Action::CancelFindReplace);
+                self.input_mode = InputMode::Normal;
+            },
+            (KeyCode::Enter | KeyCode::Tab, _) => {
+                self.input_mode = InputMode::EditingReplacement;
+            },
+            (KeyCode::Backspace, _) => {
+                self.pattern_input.pop();
+            },
+            (KeyCode::Char(c), modifiers) if !modifiers.contains(KeyModifiers::CONTROL) => {
+                self.pattern_input.push(c);
+            },
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn on_key_pressed_find_replace_replacement(&mut self, key_event: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
+                self.last_action = Some(Action::Exit);
+                self.request_exit();
+                Ok(())
+            },
+            (KeyCode::Esc, _) => {
+                self.last_action = Some(Action::CancelFindReplace);
+                self.input_mode = InputMode::Normal;
+                Ok(())
+            },
+            (KeyCode::BackTab, _) => {
+                self.input_mode = InputMode::EditingPattern;
+                Ok(())
+            },
+            (KeyCode::Backspace, _) => {
+                self.replacement_input.pop();
+                Ok(())
+            },
+            (KeyCode::Enter, _) => {
+                self.last_action = Some(Action::ReplaceRegex);
+                let pattern = self.pattern_input.clone();
+                let replacement = self.replacement_input.clone();
+                self.push_undo_snapshot();
+                let result = self.apply_regex_replace(&pattern, &replacement);
+                self.input_mode = InputMode::Normal;
+                result
+            },
+            (KeyCode::Char(c), modifiers) if !modifiers.contains(KeyModifiers::CONTROL) => {
+                self.replacement_input.push(c);
+                Ok(())
+            },
+            _ => Ok(())
+        }
     }
 
     fn request_exit(&mut self) {
@@ -160,8 +584,9 @@ impl App {
 
 impl Widget for &App {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let [instructions, textbox, audit_log, last_error] = Layout::vertical([
+        let [instructions, prompt, textbox, audit_log, last_error] = Layout::vertical([
             Constraint::Length(3), // instructions
+            Constraint::Length(3), // find & replace prompt
             Constraint::Min(5),    // text box
             Constraint::Length(3), // last action
             Constraint::Length(3), // error (optional)
@@ -173,16 +598,41 @@ impl Widget for &App {
         ]).areas(audit_log);
 
         // draw the instructions
-        let text = "<F2> paste | <F3> remove space | <F4> copy to clipboard | <F5> clear text | <CTRL+c> exit";
+        let text = match self.input_mode {
+            InputMode::Normal => self.normal_mode_instructions(),
+            InputMode::Editing => "Editing: type to insert | <Backspace>/<Delete> remove | <Left>/<Right>/<Home>/<End> move | <CTRL+z> undo | <CTRL+y> redo | <Esc> stop editing | <CTRL+c> exit".to_string(),
+            InputMode::EditingPattern => "Find & Replace: type the pattern | <Enter>/<Tab> next field | <Esc> cancel".to_string(),
+            InputMode::EditingReplacement => "Find & Replace: type the replacement | <Enter> apply | <Esc> cancel".to_string(),
+        };
         Paragraph::new(text)
             .block(Block::bordered()
-                .title("Keybinds").title_style(Style::new().fg(ACCENT_COLOR)))
+                .title(format!("Keybinds ({})", self.input_mode.as_str()))
+                .title_style(Style::new().fg(ACCENT_COLOR)))
             .render(instructions, buf);
 
+        // draw the find & replace prompt
+        let [pattern_area, replacement_area] = Layout::horizontal([
+            Constraint::Percentage(50),
+            Constraint::Percentage(50),
+        ]).areas(prompt);
+
+        Paragraph::new(format!("Pattern: {}", self.pattern_input))
+            .block(Block::bordered()
+                .title("Find (regex)")
+                .title_style(Style::new().fg(ACCENT_COLOR)))
+            .render(pattern_area, buf);
+
+        Paragraph::new(format!("Replacement: {}", self.replacement_input))
+            .block(Block::bordered()
+                .title("Replace With")
+                .title_style(Style::new().fg(ACCENT_COLOR)))
+            .render(replacement_area, buf);
+
         // draw the text from the clipboard
         Paragraph::new(self.text.as_str())
             .block(Block::bordered()
-                .title("Text Box (press F2 to paste)").title_style(Style::new().fg(Color::Red)))
+                .title(format!("Text Box (F6 to edit, F2 to paste, clipboard: {})", self.clipboard.name()))
+                .title_style(Style::new().fg(Color::Red)))
             .wrap(Wrap { trim: false }).render(textbox, buf);
 
         // draw the last pressed key
@@ -225,10 +675,21 @@ impl Widget for &App {
     }
 }
 
+/// Ensures a panic restores the terminal (raw mode off, alternate screen gone) before
+/// printing its report, instead of leaving a garbled terminal behind.
+fn install_panic_hook() {
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        ratatui::restore();
+        previous_hook(panic_info);
+    }));
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    install_panic_hook();
+
     let mut terminal = ratatui::init();
     let mut app = App::default();
-    app.clipboard = Clipboard::new().ok();
     app.run(&mut terminal)?;
     ratatui::restore();
     Ok(())